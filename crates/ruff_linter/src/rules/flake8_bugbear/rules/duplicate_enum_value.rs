@@ -0,0 +1,126 @@
+use std::collections::hash_map::Entry;
+
+use rustc_hash::FxHashMap;
+
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::comparable::ComparableExpr;
+use ruff_python_ast::{self as ast, Expr, Stmt, StmtClassDef};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `enum` classes that assign the same value to multiple members.
+///
+/// ## Why is this bad?
+/// In Python, an `enum` member that's assigned the same value as an earlier
+/// member doesn't define a new member: it becomes an alias for the first
+/// one. This is almost always a mistake, and the aliasing is easy to miss
+/// when reading the class body.
+///
+/// ## Example
+/// ```python
+/// from enum import Enum
+///
+/// class Color(Enum):
+///     RED = 1
+///     CRIMSON = 1
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from enum import Enum
+///
+/// class Color(Enum):
+///     RED = 1
+///     CRIMSON = 2
+/// ```
+#[violation]
+pub struct DuplicateEnumValue {
+    value: String,
+    original_name: String,
+}
+
+impl Violation for DuplicateEnumValue {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let DuplicateEnumValue {
+            value,
+            original_name,
+        } = self;
+        format!("Enum member should not have the same value as member `{original_name}` ({value})")
+    }
+}
+
+/// B034
+pub(crate) fn duplicate_enum_value(checker: &mut Checker, class_def: &StmtClassDef) {
+    if !is_enum_class(checker, class_def) {
+        return;
+    }
+
+    let mut seen_values: FxHashMap<ComparableExpr, &str> = FxHashMap::default();
+
+    for statement in &class_def.body {
+        let Stmt::Assign(ast::StmtAssign { targets, value, .. }) = statement else {
+            continue;
+        };
+        let [Expr::Name(ast::ExprName { id: name, .. })] = targets.as_slice() else {
+            continue;
+        };
+        if !value.is_literal_expr() {
+            continue;
+        }
+        // Sunder names (e.g. `_order_`, `_ignore_`) are reserved by the
+        // `enum` module for its own machinery and are never turned into
+        // members, so they can't alias one another or a real member.
+        if is_sunder(name) {
+            continue;
+        }
+
+        match seen_values.entry(value.into()) {
+            Entry::Occupied(entry) => {
+                checker.diagnostics.push(Diagnostic::new(
+                    DuplicateEnumValue {
+                        value: checker.generator().expr(value),
+                        original_name: (*entry.get()).to_string(),
+                    },
+                    statement.range(),
+                ));
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(name);
+            }
+        }
+    }
+}
+
+/// Returns `true` if `class_def` is (or appears to be) an `enum` class, i.e.
+/// it subclasses `enum.Enum`, `enum.IntEnum`, `enum.StrEnum`, `enum.Flag`, or
+/// `enum.IntFlag`.
+fn is_enum_class(checker: &Checker, class_def: &StmtClassDef) -> bool {
+    class_def.bases().iter().any(|base| {
+        checker
+            .semantic()
+            .resolve_qualified_name(base)
+            .is_some_and(|qualified_name| {
+                matches!(
+                    qualified_name.segments(),
+                    ["enum", "Enum" | "IntEnum" | "StrEnum" | "Flag" | "IntFlag"]
+                )
+            })
+    })
+}
+
+/// Returns `true` if `name` is a "sunder" name, e.g. `_order_` or
+/// `_ignore_`: a single leading and trailing underscore, but not a dunder
+/// (double-underscore) name. Python's `enum` module reserves these names for
+/// its own machinery, so assignments to them never become enum members.
+fn is_sunder(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    bytes.len() > 2
+        && bytes[0] == b'_'
+        && bytes[bytes.len() - 1] == b'_'
+        && bytes[1] != b'_'
+        && bytes[bytes.len() - 2] != b'_'
+}
@@ -1,10 +1,15 @@
-use ruff_python_ast::{Expr, ExprSet};
+use ruff_python_ast::{
+    Expr, ExprAttribute, ExprCall, ExprList, ExprName, ExprSet, ExprTuple, ExprUnaryOp,
+};
+use ruff_python_semantic::SemanticModel;
+use ruff_python_trivia::{SimpleTokenKind, SimpleTokenizer};
+use ruff_source_file::Locator;
 use rustc_hash::FxHashSet;
 
 use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
 use ruff_macros::{derive_message_formats, violation};
 use ruff_python_ast::comparable::ComparableExpr;
-use ruff_text_size::{Ranged, TextRange};
+use ruff_text_size::{Ranged, TextRange, TextSize};
 
 use crate::checkers::ast::Checker;
 
@@ -16,6 +21,12 @@ use crate::checkers::ast::Checker;
 /// duplicate item in a set literal is redundant, as the duplicate item will be
 /// replaced with a single item at runtime.
 ///
+/// Duplicates aren't limited to identical literals, but also include any
+/// side-effect-free expression whose value is known to be the same on every
+/// evaluation, such as attribute accesses or `frozenset` literals. The same
+/// applies to sets built via the `set(...)` and `frozenset(...)`
+/// constructors, rather than `{...}` syntax.
+///
 /// ## Example
 /// ```python
 /// {1, 2, 3, 1}
@@ -44,41 +55,59 @@ impl AlwaysFixableViolation for DuplicateValue {
 }
 
 /// B033
-pub(crate) fn duplicate_value(checker: &mut Checker, elts: &[Expr], range: TextRange) {
-    let mut seen_values: FxHashSet<ComparableExpr> = FxHashSet::default();
-    let mut duplicate_indices: Vec<usize> = Vec::new();
-    let mut unique_indices: Vec<usize> = Vec::new();
+pub(crate) fn duplicate_value(checker: &mut Checker, elts: &[Expr]) {
+    let duplicate_indices = find_duplicate_indices(elts, checker.semantic());
 
-    for (index, elt) in elts.iter().enumerate() {
-        if elt.is_literal_expr() {
-            let comparable_value: ComparableExpr = elt.into();
+    if duplicate_indices.is_empty() {
+        return;
+    }
 
-            if seen_values.insert(comparable_value) {
-                unique_indices.push(index);
-            } else {
-                duplicate_indices.push(index);
-            }
-        } else {
-            unique_indices.push(index);
-        }
+    let fix = remove_elements(elts, &duplicate_indices, checker.locator());
+
+    report_duplicates(checker, elts, &duplicate_indices, fix);
+}
+
+/// B033
+///
+/// Handles `set(...)` and `frozenset(...)` constructor calls, which build a
+/// set just as a `{...}` display does, but aren't `ExprSet` nodes.
+pub(crate) fn duplicate_value_in_set_or_frozenset_call(checker: &mut Checker, call: &ExprCall) {
+    if !call.arguments.keywords.is_empty() {
+        return;
+    }
+    let [argument] = call.arguments.args.as_ref() else {
+        return;
+    };
+    let Expr::Name(ExprName { id: func_name, .. }) = call.func.as_ref() else {
+        return;
+    };
+    if !matches!(func_name.as_str(), "set" | "frozenset") || !checker.semantic().is_builtin(func_name)
+    {
+        return;
     }
 
+    let elts = match argument {
+        Expr::List(ExprList { elts, .. })
+        | Expr::Set(ExprSet { elts, .. })
+        | Expr::Tuple(ExprTuple { elts, .. }) => elts,
+        _ => return,
+    };
+
+    let duplicate_indices = find_duplicate_indices(elts, checker.semantic());
+
     if duplicate_indices.is_empty() {
         return;
     }
 
-    let fix = Fix::safe_edit(Edit::range_replacement(
-        checker.generator().expr(&Expr::Set(ExprSet {
-            elts: unique_indices
-                .into_iter()
-                .map(|index| elts[index].clone())
-                .collect(),
-            range: TextRange::default(),
-        })),
-        range,
-    ));
-
-    for index in duplicate_indices {
+    let fix = remove_elements(elts, &duplicate_indices, checker.locator());
+
+    report_duplicates(checker, elts, &duplicate_indices, fix);
+}
+
+/// Emit a [`DuplicateValue`] diagnostic for each duplicate element, sharing
+/// the same `fix`.
+fn report_duplicates(checker: &mut Checker, elts: &[Expr], duplicate_indices: &[usize], fix: Fix) {
+    for &index in duplicate_indices {
         let elt = &elts[index];
         let mut diagnostic = Diagnostic::new(
             DuplicateValue {
@@ -92,3 +121,183 @@ pub(crate) fn duplicate_value(checker: &mut Checker, elts: &[Expr], range: TextR
         checker.diagnostics.push(diagnostic);
     }
 }
+
+/// Return the indices of the elements in `elts` that structurally duplicate
+/// an earlier element.
+fn find_duplicate_indices(elts: &[Expr], semantic: &SemanticModel) -> Vec<usize> {
+    let mut seen_values: FxHashSet<ComparableExpr> = FxHashSet::default();
+    let mut duplicate_indices: Vec<usize> = Vec::new();
+
+    for (index, elt) in elts.iter().enumerate() {
+        if is_pure_comparable_expr(elt, semantic) {
+            let comparable_value: ComparableExpr = elt.into();
+
+            if !seen_values.insert(comparable_value) {
+                duplicate_indices.push(index);
+            }
+        }
+    }
+
+    duplicate_indices
+}
+
+/// Build a [`Fix`] that deletes each duplicate element (along with its
+/// separating comma) from `elts`, leaving the surviving elements — and any
+/// interior comments or formatting — untouched.
+///
+/// This avoids re-serializing the whole display (which would discard
+/// comments and collapse the original formatting onto one line) in favor of
+/// one small [`Edit`] per duplicate, or one per run of adjacent duplicates.
+fn remove_elements(elts: &[Expr], duplicate_indices: &[usize], locator: &Locator) -> Fix {
+    let is_duplicate: FxHashSet<usize> = duplicate_indices.iter().copied().collect();
+
+    let mut edits = Vec::with_capacity(duplicate_indices.len());
+    let mut index = 0;
+    while index < elts.len() {
+        if !is_duplicate.contains(&index) {
+            index += 1;
+            continue;
+        }
+
+        // Merge a run of adjacent duplicates into a single edit, since the
+        // comma between them would otherwise be claimed by two edits.
+        let run_start = index;
+        while index + 1 < elts.len() && is_duplicate.contains(&(index + 1)) {
+            index += 1;
+        }
+        let run_end = index;
+        index += 1;
+
+        let start = elts[run_start].start();
+        let end = elts[run_end].end();
+
+        let edit = if run_end + 1 < elts.len() {
+            // A surviving element follows: delete through its leading comma,
+            // plus any horizontal whitespace right after it, so we don't
+            // leave a doubled separator behind (e.g. `{1, 1, 2}` should
+            // become `{1, 2}`, not `{1,  2}`). Stop at the first newline so a
+            // trailing same-line comment on the deleted element isn't
+            // swallowed along with a comment that belongs to what follows.
+            let comma = SimpleTokenizer::starts_at(end, locator.contents())
+                .find(|token| token.kind() == SimpleTokenKind::Comma)
+                .expect("expected comma after duplicate element");
+            let end = skip_trailing_horizontal_whitespace(comma.end(), locator.contents());
+            Edit::deletion(start, end)
+        } else {
+            // This run reaches the end of `elts`. The first occurrence of a
+            // value is never marked a duplicate (see `find_duplicate_indices`),
+            // so a run reaching the end can't also start at the very first
+            // element.
+            debug_assert!(run_start > 0, "a trailing run can't start at index 0");
+
+            let preceding_comma =
+                SimpleTokenizer::starts_at(elts[run_start - 1].end(), locator.contents())
+                    .find(|token| token.kind() == SimpleTokenKind::Comma)
+                    .expect("expected comma before duplicate element");
+
+            // Normally we can delete back through that comma (e.g. the doc
+            // example `{1, 2, 3, 1}` becomes `{1, 2, 3}`). But if the
+            // preceding (surviving) element has a trailing same-line
+            // comment between its comma and this run, deleting through the
+            // comma would swallow that comment. In that case, leave the
+            // comma in place — it becomes the new trailing separator — and
+            // only delete the run itself, plus its own trailing comma and
+            // horizontal whitespace, if any.
+            let has_trailing_comment = SimpleTokenizer::starts_at(preceding_comma.end(), locator.contents())
+                .take_while(|token| token.start() < start)
+                .any(|token| token.kind() == SimpleTokenKind::Comment);
+
+            if has_trailing_comment {
+                // Look only at the next meaningful token: if this run has
+                // its own trailing comma (e.g. under the "magic trailing
+                // comma" style), consume it; otherwise (e.g. a closing
+                // brace follows directly) there's nothing more to remove.
+                // We deliberately don't search further, since that could
+                // otherwise walk straight past the closing bracket.
+                let next_token = SimpleTokenizer::starts_at(end, locator.contents())
+                    .find(|token| !token.kind().is_trivia());
+                match next_token.filter(|token| token.kind() == SimpleTokenKind::Comma) {
+                    Some(trailing_comma) => Edit::deletion(
+                        start,
+                        skip_trailing_horizontal_whitespace(trailing_comma.end(), locator.contents()),
+                    ),
+                    None => Edit::range_deletion(TextRange::new(start, end)),
+                }
+            } else {
+                Edit::deletion(preceding_comma.start(), end)
+            }
+        };
+
+        edits.push(edit);
+    }
+
+    let mut edits = edits.into_iter();
+    let first = edits.next().expect("at least one duplicate element");
+    Fix::safe_edits(first, edits)
+}
+
+/// Advance `offset` past any ASCII spaces or tabs that immediately follow it
+/// in `text`, without crossing a newline (so a trailing same-line comment
+/// isn't swallowed).
+fn skip_trailing_horizontal_whitespace(offset: TextSize, text: &str) -> TextSize {
+    let trailing_len = text[usize::from(offset)..]
+        .bytes()
+        .take_while(|byte| matches!(byte, b' ' | b'\t'))
+        .count();
+    offset + TextSize::try_from(trailing_len).unwrap()
+}
+
+/// Returns `true` if `expr` is guaranteed to be side-effect-free and can
+/// therefore be compared structurally (via [`ComparableExpr`]) to detect
+/// duplicate set members, even when it isn't a literal.
+///
+/// This covers dotted-name attribute accesses (e.g. `foo.bar.BAR`), unary
+/// operations on such expressions (e.g. `-1`), and tuples or `frozenset(...)`
+/// calls built entirely out of such expressions. Calls other than a
+/// non-shadowed `frozenset`, comprehensions, starred unpacking, and bare
+/// names are excluded, since evaluating them twice isn't guaranteed to
+/// produce the same (or any) value.
+fn is_pure_comparable_expr(expr: &Expr, semantic: &SemanticModel) -> bool {
+    if expr.is_literal_expr() {
+        return true;
+    }
+
+    match expr {
+        Expr::UnaryOp(ExprUnaryOp { operand, .. }) => is_pure_comparable_expr(operand, semantic),
+        // Restrict to a chain of attribute accesses rooted at a bare name
+        // (e.g. `foo.BAR`, `foo.bar.BAR`): the base could otherwise be an
+        // arbitrary expression, like a `Call`, with side effects and no
+        // guarantee of returning an equal value on each evaluation.
+        Expr::Attribute(_) => is_dotted_name(expr),
+        Expr::Tuple(ExprTuple { elts, .. }) => elts
+            .iter()
+            .all(|elt| is_pure_comparable_expr(elt, semantic)),
+        Expr::Call(ExprCall {
+            func, arguments, ..
+        }) => {
+            matches!(func.as_ref(), Expr::Name(ExprName { id, .. }) if id == "frozenset")
+                && semantic.is_builtin("frozenset")
+                && arguments.keywords.is_empty()
+                && match arguments.args.as_ref() {
+                    [] => true,
+                    [Expr::List(ExprList { elts, .. })
+                    | Expr::Set(ExprSet { elts, .. })
+                    | Expr::Tuple(ExprTuple { elts, .. })] => elts
+                        .iter()
+                        .all(|elt| is_pure_comparable_expr(elt, semantic)),
+                    _ => false,
+                }
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `expr` is a chain of attribute accesses rooted at a bare
+/// name, e.g. `foo`, `foo.bar`, or `foo.bar.baz`.
+fn is_dotted_name(expr: &Expr) -> bool {
+    match expr {
+        Expr::Name(_) => true,
+        Expr::Attribute(ExprAttribute { value, .. }) => is_dotted_name(value),
+        _ => false,
+    }
+}